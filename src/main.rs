@@ -1,29 +1,58 @@
+use async_zip::tokio::read::seek::ZipFileReader;
 use clap::{Parser, Subcommand};
+use futures_util::StreamExt;
 use std::fs::{self, File};
-use std::io::Write;
-use std::path::Path; // 移除未使用的 PathBuf 导入
+use std::io::{Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use zip::ZipArchive;
 use anyhow::{Result, Context};
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use sha2::{Digest, Sha256};
 use std::time::Duration; // 导入 Duration 类型以正确设置进度条
+use tokio::io::BufReader;
+use tokio::sync::Semaphore;
+use tokio_util::compat::FuturesAsyncReadCompatExt;
 // 主入口文件 main.rs
 #[derive(Parser)]
 #[command(name = "奥科戈包管理器", about = "简单的中文包管理工具")]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// HTTP/HTTPS 代理地址（可选），未指定时回退读取 HTTP_PROXY / HTTPS_PROXY 环境变量
+    #[arg(long, global = true)]
+    proxy: Option<String>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    /// 获取并安装指定包（下载 + 解压）
+    /// 获取并安装指定包（下载 + 解压），支持同时传入多个包名并发安装
     Get {
-        /// 包名
-        package: String,
+        /// 包名（可传入多个，将并发下载安装）
+        #[arg(required = true)]
+        packages: Vec<String>,
 
-        /// ZIP 文件名（可选），例如 mypkg.zip
-        #[arg(short, long)]
+        /// ZIP 文件名（可选，仅在只安装单个包时生效），例如 mypkg.zip
+        #[arg(short, long, conflicts_with = "git")]
         file: Option<String>,
+
+        /// 预期的 SHA-256 校验值（可选，仅在只安装单个包时生效）；不提供时会尝试下载
+        /// `<zip>.sha256` 进行校验
+        #[arg(long, conflicts_with = "git")]
+        sha256: Option<String>,
+
+        /// 从 Git 仓库安装（仅支持单个包），与 --file 互斥
+        #[arg(long, conflicts_with = "file")]
+        git: Option<String>,
+
+        /// 指定要检出的分支（与 --revision 互斥）
+        #[arg(long, requires = "git")]
+        branch: Option<String>,
+
+        /// 指定要检出的提交（与 --branch 互斥）
+        #[arg(long, requires = "git")]
+        revision: Option<String>,
     },
 
     /// 删除指定包（卸载）
@@ -37,28 +66,316 @@ enum Commands {
         /// 包名
         package: String,
     },
+
+    /// 检查并更新 aokege 自身到最新版本
+    SelfUpdate,
 }
 
 const BASE_URL: &str = "https://aokege.github.io/zhucechu";
 const BASE_DIR: &str = "./packages";
+/// 同时进行的下载任务数上限
+const MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// 构建 `reqwest::Client`：优先使用 `--proxy` 传入的地址，否则回退读取
+/// `HTTP_PROXY` / `HTTPS_PROXY` 环境变量；都没有时返回直连客户端。
+fn build_client(proxy: Option<&str>) -> Result<reqwest::Client> {
+    let proxy_url = proxy
+        .map(|s| s.to_string())
+        .or_else(|| std::env::var("HTTPS_PROXY").ok())
+        .or_else(|| std::env::var("HTTP_PROXY").ok())
+        .or_else(|| std::env::var("https_proxy").ok())
+        .or_else(|| std::env::var("http_proxy").ok());
+
+    let Some(proxy_url) = proxy_url else {
+        return Ok(reqwest::Client::new());
+    };
+
+    reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(&proxy_url).context("代理地址无效")?)
+        .build()
+        .context("构建带代理的 HTTP 客户端失败")
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match &cli.command {
-        Commands::Get { package, file } => {
-            install_package(package, file.as_deref()).await?
+        Commands::Get {
+            packages,
+            file,
+            sha256,
+            git,
+            branch,
+            revision,
+        } => {
+            if let Some(url) = git {
+                if packages.len() != 1 {
+                    anyhow::bail!("--git 仅支持安装单个包");
+                }
+                let source = GitSource {
+                    url: url.clone(),
+                    branch: branch.clone(),
+                    revision: revision.clone(),
+                };
+                install_from_git(&packages[0], &source)?
+            } else if packages.len() == 1 {
+                let client = build_client(cli.proxy.as_deref())?;
+                install_package(&client, &packages[0], file.as_deref(), sha256.as_deref(), None)
+                    .await?
+            } else {
+                if file.is_some() || sha256.is_some() {
+                    anyhow::bail!("--file 和 --sha256 仅支持安装单个包");
+                }
+                install_packages(packages, cli.proxy.as_deref()).await?
+            }
         }
         Commands::Remove { package } => uninstall_package(package)?,
         Commands::Extract { package } => unzip_package(package)?,
+        Commands::SelfUpdate => self_update(cli.proxy.as_deref()).await?,
+    }
+
+    Ok(())
+}
+
+/// Git 安装源：仓库地址 + 可选的分支 / 提交。
+///
+/// `branch` 与 `revision` 互斥；若两者都未指定，保留 `git clone` 检出的仓库默认分支。
+struct GitSource {
+    url: String,
+    branch: Option<String>,
+    revision: Option<String>,
+}
+
+impl GitSource {
+    fn validate(&self) -> Result<()> {
+        if self.branch.is_some() && self.revision.is_some() {
+            anyhow::bail!("branch 和 revision 不能同时指定");
+        }
+        Ok(())
+    }
+}
+
+/// 从 Git 仓库安装包：克隆到 `./packages/<package>`，再按需检出分支或提交。
+fn install_from_git(package: &str, source: &GitSource) -> Result<()> {
+    source.validate()?;
+
+    let output_dir = Path::new(BASE_DIR).join(package);
+    std::fs::create_dir_all(BASE_DIR)?;
+
+    if output_dir.exists() {
+        anyhow::bail!("目标目录已存在: {:?}，请先执行 remove", output_dir);
+    }
+
+    println!("⬇️ 正在克隆: {}", source.url);
+    let status = std::process::Command::new("git")
+        .args(["clone", &source.url, &output_dir.to_string_lossy()])
+        .status()
+        .context("执行 git clone 失败")?;
+    if !status.success() {
+        anyhow::bail!("git clone 失败，退出码: {:?}", status.code());
+    }
+
+    if let Some(revision) = &source.revision {
+        checkout(&output_dir, revision)?;
+    } else if let Some(branch) = &source.branch {
+        checkout(&output_dir, branch)?;
+    }
+    // 两者均未指定时不做额外操作：git clone 已经检出了仓库自己的默认分支，
+    // 不一定是 master/main（如 develop、trunk），强行检出反而可能让成功的克隆报错
+
+    println!("✅ 安装完成: {:?}", output_dir);
+    Ok(())
+}
+
+fn checkout(repo_dir: &Path, reference: &str) -> Result<()> {
+    let status = std::process::Command::new("git")
+        .args(["-C", &repo_dir.to_string_lossy(), "checkout", reference])
+        .status()
+        .context("执行 git checkout 失败")?;
+    if !status.success() {
+        anyhow::bail!("git checkout {} 失败，退出码: {:?}", reference, status.code());
+    }
+    Ok(())
+}
+
+/// 检查 aokege 自身是否有新版本，若有则下载、校验并原地替换当前可执行文件。
+///
+/// 流程与包安装的“下载 -> 解压 -> 落地”思路一致：下载到临时目录，解压出新二进制，
+/// 拷贝到当前可执行文件所在目录（避免跨文件系统 rename 失败），再把当前可执行文件
+/// 移到旁边的 `.old` 备份，最后把新二进制原子地改名到位。
+async fn self_update(proxy: Option<&str>) -> Result<()> {
+    let client = build_client(proxy)?;
+    let current_version = env!("CARGO_PKG_VERSION");
+
+    let latest_version = fetch_latest_version(&client).await?;
+    if !is_newer_version(&latest_version, current_version) {
+        println!("✅ 当前已是最新版本: {}", current_version);
+        return Ok(());
+    }
+
+    println!("⬆️ 发现新版本: {} -> {}", current_version, latest_version);
+
+    let asset_name = self_update_asset_name();
+    let url = format!("{BASE_URL}/releases/{latest_version}/{asset_name}");
+
+    // 按版本号隔离临时目录，避免跨版本运行残留的半截压缩包被当成断点续传的基础，
+    // 导致拼接出一个对不上任何一个版本的压缩包
+    let tmp_dir = std::env::temp_dir()
+        .join("aokege-self-update")
+        .join(&latest_version);
+    std::fs::create_dir_all(&tmp_dir)?;
+    let archive_path = tmp_dir.join(&asset_name);
+
+    let digest = download_with_resume(&client, &url, &archive_path, None).await?;
+    // 替换可执行文件风险远高于安装普通包，找不到校验和时必须中止，而不是警告后继续
+    verify_checksum(&client, "aokege-self-update", &url, &archive_path, &digest, None, true).await?;
+
+    println!("📦 正在解压新版本...");
+    let extract_dir = tmp_dir.join("extracted");
+    unzip_from_path_async(&archive_path, &extract_dir).await?;
+
+    let new_binary = extract_dir.join(self_update_binary_name());
+    if !new_binary.exists() {
+        anyhow::bail!("解压后未找到新版本可执行文件: {:?}", new_binary);
+    }
+
+    let current_exe = std::env::current_exe().context("获取当前可执行文件路径失败")?;
+    let exe_dir = current_exe
+        .parent()
+        .context("无法确定当前可执行文件所在目录")?;
+
+    // `new_binary` 位于系统临时目录（常是 tmpfs），与 `current_exe` 往往不在同一文件系统，
+    // 直接 rename 会报 EXDEV。先 copy 到目标同目录下的一个临时文件，使最终替换落在同一文件系统上，
+    // 从而能用 rename 原子完成
+    let staged_exe = exe_dir.join(format!(".{}.new", self_update_binary_name()));
+    std::fs::copy(&new_binary, &staged_exe).context("拷贝新版本可执行文件到目标目录失败")?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&staged_exe)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&staged_exe, perms)?;
+    }
+
+    let backup_exe = current_exe.with_extension("old");
+    std::fs::rename(&current_exe, &backup_exe).context("备份当前可执行文件失败")?;
+    if let Err(e) = std::fs::rename(&staged_exe, &current_exe) {
+        // 替换失败时回滚，避免留下一个无法运行的可执行文件
+        let _ = std::fs::rename(&backup_exe, &current_exe);
+        return Err(e).context("替换可执行文件失败");
+    }
+
+    let _ = std::fs::remove_file(&backup_exe);
+    println!(
+        "✅ 更新完成: {} -> {}，请重新运行 aokege 以使用新版本",
+        current_version, latest_version
+    );
+    Ok(())
+}
+
+async fn fetch_latest_version(client: &reqwest::Client) -> Result<String> {
+    let url = format!("{BASE_URL}/releases/latest_version.txt");
+    let res = client.get(&url).send().await.context("获取最新版本号失败")?;
+    if !res.status().is_success() {
+        anyhow::bail!("获取最新版本号失败，状态码: {}", res.status());
+    }
+    let text = res.text().await.context("读取版本号内容失败")?;
+    Ok(text.trim().to_string())
+}
+
+/// 仅支持形如 `x.y.z` 的严格递增语义化版本比较；解析失败时保守地认为二者不同即视为更新。
+fn is_newer_version(candidate: &str, current: &str) -> bool {
+    fn parse(version: &str) -> Option<(u64, u64, u64)> {
+        let mut parts = version.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next()?.parse().ok()?;
+        Some((major, minor, patch))
+    }
+
+    match (parse(candidate), parse(current)) {
+        (Some(a), Some(b)) => a > b,
+        _ => candidate != current,
+    }
+}
+
+fn self_update_asset_name() -> String {
+    format!(
+        "aokege-{}-{}.zip",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    )
+}
+
+fn self_update_binary_name() -> &'static str {
+    if cfg!(windows) {
+        "aokege.exe"
+    } else {
+        "aokege"
+    }
+}
+
+/// 并发安装多个包：共用一个 `reqwest::Client`，以信号量限制同时进行的下载数，
+/// 并为每个包在 `MultiProgress` 中分配独立的进度条。安装结果逐包收集，
+/// 单个包失败不会中止整批任务。
+async fn install_packages(packages: &[String], proxy: Option<&str>) -> Result<()> {
+    let client = build_client(proxy)?;
+    let multi = Arc::new(MultiProgress::new());
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_DOWNLOADS));
+
+    // 同一个包名重复出现会让多个任务并发写同一个 zip_path/output_dir，互相踩踏；
+    // 这里按出现顺序去重后再派发任务
+    let mut seen = std::collections::HashSet::new();
+    let packages: Vec<&String> = packages.iter().filter(|p| seen.insert(p.as_str())).collect();
+
+    let mut tasks = Vec::new();
+    for package in packages {
+        let client = client.clone();
+        let multi = multi.clone();
+        let semaphore = semaphore.clone();
+        let package = package.clone();
+
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("信号量已关闭");
+            let result = install_package(&client, &package, None, None, Some(&multi)).await;
+            (package, result)
+        }));
+    }
+
+    let mut succeeded = Vec::new();
+    let mut failed = Vec::new();
+    for task in tasks {
+        let (package, result) = task.await.context("安装任务异常终止")?;
+        match result {
+            Ok(()) => succeeded.push(package),
+            Err(e) => failed.push((package, e)),
+        }
+    }
+
+    println!("\n📋 安装结果汇总:");
+    for package in &succeeded {
+        println!("  ✅ {}", package);
+    }
+    for (package, e) in &failed {
+        println!("  ❌ {}: {}", package, e);
+    }
+
+    if !failed.is_empty() {
+        anyhow::bail!("{} 个包安装失败", failed.len());
     }
 
     Ok(())
 }
 
-async fn install_package(package: &str, filename: Option<&str>) -> Result<()> {
-    
+async fn install_package(
+    client: &reqwest::Client,
+    package: &str,
+    filename: Option<&str>,
+    expected_sha256: Option<&str>,
+    multi: Option<&MultiProgress>,
+) -> Result<()> {
+
     let default_zip_name = format!("{package}.zip");
     let zip_name = filename.unwrap_or(&default_zip_name);
     let url = format!("{BASE_URL}/zujian/{package}/{zip_name}");
@@ -69,38 +386,194 @@ async fn install_package(package: &str, filename: Option<&str>) -> Result<()> {
     println!("⬇️ 正在下载: {}", url);
     std::fs::create_dir_all(BASE_DIR)?;
 
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::with_template("{spinner:.green} {msg}")
-            .unwrap()
-            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"),
-    );
-    // 修复：`enable_steady_tick` 需要一个 `Duration` 类型
-    pb.enable_steady_tick(Duration::from_millis(120)); 
-    pb.set_message("下载中...");
+    let digest = download_with_resume(client, &url, &zip_path, multi).await?;
+    verify_checksum(client, package, &url, &zip_path, &digest, expected_sha256, false).await?;
+
+    println!("📦 正在解压...");
+    unzip_from_path_async(&zip_path, &output_dir).await?;
+    println!("✅ 安装完成: {:?}", output_dir);
+
+    Ok(())
+}
+
+/// 校验下载内容的 SHA-256：优先使用 `--sha256` 传入的期望值，否则尝试获取
+/// `<zip>.sha256` 兄弟文件。若 `required` 为 `false` 且两者都不可用，跳过校验并提示；
+/// 若 `required` 为 `true`（例如 self-update 替换可执行文件），找不到校验和则视为硬错误。
+/// 校验通过后，将摘要与实际下载到的文件名一并保存到 `<package>.sha256`（`--file` 可能
+/// 让它与默认的 `<package>.zip` 不同），供 `Remove` 在卸载前做篡改检测。
+async fn verify_checksum(
+    client: &reqwest::Client,
+    package: &str,
+    url: &str,
+    zip_path: &Path,
+    actual_digest: &str,
+    expected_sha256: Option<&str>,
+    required: bool,
+) -> Result<()> {
+    let expected = match expected_sha256 {
+        Some(hex) => Some(hex.to_lowercase()),
+        None => fetch_sidecar_sha256(client, url).await?,
+    };
+
+    let Some(expected) = expected else {
+        if required {
+            let _ = std::fs::remove_file(zip_path);
+            anyhow::bail!("未找到校验和（缺少 {}.sha256 且未提供 --sha256），拒绝继续", url);
+        }
+        println!("⚠️ 未找到校验和，跳过完整性校验");
+        return Ok(());
+    };
+
+    if !expected.eq_ignore_ascii_case(actual_digest) {
+        let _ = std::fs::remove_file(zip_path);
+        anyhow::bail!(
+            "SHA-256 校验失败: 期望 {}，实际 {}",
+            expected,
+            actual_digest
+        );
+    }
+
+    println!("🔒 校验和匹配: {}", actual_digest);
+    let zip_name = zip_path.file_name().and_then(|f| f.to_str()).unwrap_or_default();
+    let checksum_path = Path::new(BASE_DIR).join(format!("{package}.sha256"));
+    std::fs::write(&checksum_path, format!("{actual_digest}  {zip_name}\n"))
+        .context("保存校验和失败")?;
+
+    Ok(())
+}
 
-    let res = reqwest::get(&url).await.context("网络请求失败")?;
+async fn fetch_sidecar_sha256(client: &reqwest::Client, url: &str) -> Result<Option<String>> {
+    let sha_url = format!("{url}.sha256");
+    let res = client.get(&sha_url).send().await.context("获取校验和失败")?;
+    if res.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
     if !res.status().is_success() {
-        pb.finish_and_clear();
+        anyhow::bail!("获取校验和失败，状态码: {}", res.status());
+    }
+
+    let text = res.text().await.context("读取校验和内容失败")?;
+    let hex = text
+        .split_whitespace()
+        .next()
+        .map(|s| s.to_lowercase())
+        .filter(|s| !s.is_empty());
+
+    Ok(hex)
+}
+
+/// 以流式方式下载文件，支持断点续传。
+///
+/// 若 `dest` 已存在部分内容，则发送 `Range` 请求头从断点处继续下载；
+/// 若服务器未返回 `Content-Length`（例如不支持 `HEAD`），则退化为不确定进度的 spinner。
+async fn download_with_resume(
+    client: &reqwest::Client,
+    url: &str,
+    dest: &Path,
+    multi: Option<&MultiProgress>,
+) -> Result<String> {
+    let head = client.head(url).send().await.context("HEAD 请求失败")?;
+    let total_size = head
+        .headers()
+        .get(reqwest::header::CONTENT_LENGTH)
+        .filter(|_| head.status().is_success())
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+
+    let resume_from = if dest.exists() {
+        dest.metadata().map(|m| m.len()).unwrap_or(0)
+    } else {
+        0
+    };
+
+    // 本地文件长度已达到服务器报告的总大小：说明是重复执行 get（例如包已装过），
+    // 直接复用本地文件而不是再发一次必然被服务器拒绝的续传请求
+    if let Some(total) = total_size {
+        if resume_from > 0 && resume_from >= total {
+            return hash_existing_file(dest);
+        }
+    }
+
+    let mut request = client.get(url);
+    if resume_from > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", resume_from));
+    }
+
+    let res = request.send().await.context("网络请求失败")?;
+    if res.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        // 服务器认为续传区间超出文件范围，通常意味着本地文件已经是完整的
+        return hash_existing_file(dest);
+    }
+    if !res.status().is_success() && res.status() != reqwest::StatusCode::PARTIAL_CONTENT {
         anyhow::bail!("请求失败，状态码: {}", res.status());
     }
-    let bytes = res.bytes().await.context("读取内容失败")?;
 
-    let mut file = File::create(&zip_path)?;
-    file.write_all(&bytes)?;
-    pb.finish_with_message("✅ 下载完成");
+    let pb = match total_size {
+        Some(total) => {
+            let pb = ProgressBar::new(total);
+            pb.set_style(
+                ProgressStyle::with_template(
+                    "{bar:40.cyan/blue} {bytes}/{total_bytes} ({eta})",
+                )
+                .unwrap(),
+            );
+            pb.set_position(resume_from);
+            pb
+        }
+        None => {
+            let pb = ProgressBar::new_spinner();
+            pb.set_style(
+                ProgressStyle::with_template("{spinner:.green} {msg}")
+                    .unwrap()
+                    .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"),
+            );
+            // 修复：`enable_steady_tick` 需要一个 `Duration` 类型
+            pb.enable_steady_tick(Duration::from_millis(120));
+            pb.set_message("下载中...");
+            pb
+        }
+    };
+    let pb = match multi {
+        Some(multi) => multi.add(pb),
+        None => pb,
+    };
 
-    println!("📦 正在解压...");
-    unzip_from_path(&zip_path, &output_dir)?;
-    println!("✅ 安装完成: {:?}", output_dir);
+    let mut hasher = Sha256::new();
 
-    Ok(())
+    let mut file = if resume_from > 0 && res.status() == reqwest::StatusCode::PARTIAL_CONTENT {
+        // 断点续传时，已写入磁盘的部分同样要计入摘要，否则校验和只覆盖本次续传的字节
+        hasher.update(&std::fs::read(dest).context("读取已下载内容失败")?);
+        let mut f = std::fs::OpenOptions::new().append(true).open(dest)?;
+        f.seek(SeekFrom::End(0))?;
+        f
+    } else {
+        File::create(dest)?
+    };
+
+    let mut stream = res.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.context("读取数据块失败")?;
+        file.write_all(&chunk)?;
+        hasher.update(&chunk);
+        pb.inc(chunk.len() as u64);
+    }
+
+    pb.finish_with_message("✅ 下载完成");
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// 对已存在的本地文件计算 SHA-256，用于“本地文件已完整，无需重新下载”的场景。
+fn hash_existing_file(path: &Path) -> Result<String> {
+    println!("ℹ️ 本地文件已是最新，跳过重复下载");
+    let bytes = std::fs::read(path).context("读取本地文件失败")?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
 }
 
 fn uninstall_package(package: &str) -> Result<()> {
     let dir = Path::new(BASE_DIR).join(package);
 
     if dir.exists() && dir.is_dir() {
+        warn_if_tampered(package)?;
         fs::remove_dir_all(&dir).context("删除包文件夹失败")?;
         println!("🗑️ 成功卸载包: {}", package);
     } else {
@@ -110,6 +583,37 @@ fn uninstall_package(package: &str) -> Result<()> {
     Ok(())
 }
 
+/// 若安装时记录过校验和，卸载前重新计算本地 ZIP 的摘要并比对，
+/// 不一致时仅给出警告，不阻止卸载（ZIP 本就可能已被用户手动清理）。
+///
+/// 校验和文件中同时记录了安装时实际使用的文件名（`--file` 可能让它不同于
+/// `<package>.zip`），因此这里按记录的文件名去找，而不是硬编码默认命名。
+fn warn_if_tampered(package: &str) -> Result<()> {
+    let checksum_path = Path::new(BASE_DIR).join(format!("{package}.sha256"));
+    let Ok(content) = fs::read_to_string(&checksum_path) else {
+        return Ok(());
+    };
+
+    let mut parts = content.split_whitespace();
+    let Some(expected) = parts.next() else {
+        return Ok(());
+    };
+    let default_zip_name = format!("{package}.zip");
+    let zip_name = parts.next().unwrap_or(default_zip_name.as_str());
+    let zip_path = Path::new(BASE_DIR).join(zip_name);
+    if !zip_path.exists() {
+        return Ok(());
+    }
+
+    let bytes = fs::read(&zip_path).context("读取本地压缩包失败")?;
+    let actual = format!("{:x}", Sha256::digest(&bytes));
+    if !expected.eq_ignore_ascii_case(&actual) {
+        println!("⚠️ 警告: {} 的本地压缩包与安装时记录的校验和不一致，文件可能已被修改", package);
+    }
+
+    Ok(())
+}
+
 fn unzip_package(package: &str) -> Result<()> {
     let zip_path = Path::new(BASE_DIR).join(format!("{package}.zip"));
     let output_dir = Path::new(BASE_DIR).join(package);
@@ -119,19 +623,38 @@ fn unzip_package(package: &str) -> Result<()> {
     }
 
     println!("📦 正在解压: {:?}", zip_path);
-    unzip_from_path(&zip_path, &output_dir)?;
+    // `Extract` 针对已下载到磁盘的压缩包，没有与下载重叠的收益，继续走同步解压路径
+    unzip_from_path_sync(&zip_path, &output_dir)?;
     println!("✅ 解压完成: {:?}", output_dir);
 
     Ok(())
 }
 
-fn unzip_from_path(zip_path: &Path, output_dir: &Path) -> Result<()> {
+/// 将压缩包条目名安全地拼接到 `output_dir` 下：拒绝绝对路径与包含 `..` 的条目，
+/// 防止恶意压缩包（zip-slip）借助 `../` 或绝对路径写出到解压目录之外。
+fn safe_extract_path(output_dir: &Path, entry_name: &str) -> Result<PathBuf> {
+    let entry_path = Path::new(entry_name);
+    if entry_path.is_absolute() {
+        anyhow::bail!("压缩包条目使用了绝对路径，拒绝解压: {}", entry_name);
+    }
+    if entry_path
+        .components()
+        .any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        anyhow::bail!("压缩包条目包含非法的上级目录引用，拒绝解压: {}", entry_name);
+    }
+    Ok(output_dir.join(entry_path))
+}
+
+/// 同步解压：基于 `zip` crate 逐项读取并拷贝。仅用于 `Extract` 子命令这类一次性、
+/// 已落盘压缩包的场景；会阻塞当前线程，不适合安装流程中与下载重叠执行。
+fn unzip_from_path_sync(zip_path: &Path, output_dir: &Path) -> Result<()> {
     let file = File::open(zip_path).context("打开压缩包失败")?;
     let mut archive = ZipArchive::new(file).context("读取压缩包失败")?;
 
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)?;
-        let outpath = output_dir.join(file.name());
+        let outpath = safe_extract_path(output_dir, file.name())?;
 
         if file.is_dir() {
             std::fs::create_dir_all(&outpath)?;
@@ -146,3 +669,42 @@ fn unzip_from_path(zip_path: &Path, output_dir: &Path) -> Result<()> {
 
     Ok(())
 }
+
+/// 异步解压：基于 `async_zip`，在 tokio 运行时上流式读取每个条目并写盘，
+/// 解压期间不阻塞执行器，可与并发下载重叠执行。安装流程（`Get`）使用此路径。
+async fn unzip_from_path_async(zip_path: &Path, output_dir: &Path) -> Result<()> {
+    let file = tokio::fs::File::open(zip_path)
+        .await
+        .context("打开压缩包失败")?;
+    let mut reader = ZipFileReader::with_tokio(BufReader::new(file))
+        .await
+        .context("读取压缩包失败")?;
+
+    let entry_count = reader.file().entries().len();
+    for index in 0..entry_count {
+        let entry = reader.file().entries().get(index).context("无效的压缩包条目")?;
+        let filename = entry
+            .filename()
+            .as_str()
+            .context("压缩包条目文件名编码无效")?
+            .to_string();
+        let outpath = safe_extract_path(output_dir, &filename)?;
+
+        if filename.ends_with('/') {
+            tokio::fs::create_dir_all(&outpath).await?;
+            continue;
+        }
+
+        if let Some(parent) = outpath.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        // async_zip 的条目读取器只实现 futures_io::AsyncRead，需要 compat 适配层才能喂给 tokio::io::copy
+        let entry_reader = reader.reader_with_entry(index).await.context("读取压缩包条目失败")?;
+        let mut entry_reader = entry_reader.compat();
+        let mut outfile = tokio::fs::File::create(&outpath).await?;
+        tokio::io::copy(&mut entry_reader, &mut outfile).await?;
+    }
+
+    Ok(())
+}